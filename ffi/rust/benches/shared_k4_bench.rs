@@ -0,0 +1,54 @@
+// Benchmarks read scalability of `SharedK4` across a growing number of
+// concurrent reader threads, to show that `get` under the shared read
+// lock does not serialize the way a single mutex would.
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion};
+use k4::{SharedK4, K4};
+use std::sync::Arc;
+use std::thread;
+
+fn seed_db(dir: &std::path::Path) -> Arc<SharedK4> {
+    let mut db = K4::open(dir.to_str().unwrap(), 1000, 60, false, false).expect("open db");
+
+    for i in 0..1_000u32 {
+        db.put(&i.to_be_bytes(), b"value", 0).expect("seed put");
+    }
+
+    Arc::new(SharedK4::new(db))
+}
+
+fn bench_concurrent_reads(c: &mut Criterion) {
+    let mut group = c.benchmark_group("shared_k4_concurrent_get");
+
+    for &readers in &[1usize, 2, 4, 8] {
+        let tmp_dir = tempfile::tempdir().expect("tempdir");
+        let shared = seed_db(tmp_dir.path());
+
+        group.bench_with_input(
+            BenchmarkId::from_parameter(readers),
+            &readers,
+            |b, &readers| {
+                b.iter(|| {
+                    let handles: Vec<_> = (0..readers)
+                        .map(|_| {
+                            let shared = Arc::clone(&shared);
+                            thread::spawn(move || {
+                                for i in 0..1_000u32 {
+                                    shared.get(&i.to_be_bytes()).expect("get");
+                                }
+                            })
+                        })
+                        .collect();
+
+                    for handle in handles {
+                        handle.join().expect("reader thread panicked");
+                    }
+                });
+            },
+        );
+    }
+
+    group.finish();
+}
+
+criterion_group!(benches, bench_concurrent_reads);
+criterion_main!(benches);